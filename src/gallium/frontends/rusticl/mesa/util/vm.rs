@@ -2,32 +2,130 @@ use std::{
     num::NonZeroU64,
     ops::{Deref, DerefMut},
     pin::Pin,
-    sync::Mutex,
+    sync::{Arc, Mutex, Weak},
 };
 
+#[cfg(debug_assertions)]
+use std::collections::BTreeMap;
+
 use mesa_rust_gen::*;
 
 pub struct VMInner {
     vm: Pin<Box<util_vma_heap>>,
+    // Debug-only record of every address -> size handed out by alloc() and
+    // not yet freed, so free() can catch double-frees/partial-overlap frees
+    // at the point of the bug instead of letting them corrupt util_vma_heap
+    // silently, and drop() can catch leaked ranges.
+    #[cfg(debug_assertions)]
+    live: BTreeMap<u64, u64>,
 }
 
 // SAFETY: util_vma_heap is safe to be send between threads.
 unsafe impl Send for VMInner {}
 
 impl VMInner {
-    pub fn alloc(&mut self, size: u64, alignment: u64) -> Option<VMA> {
+    pub fn alloc(&mut self, size: u64, alignment: u64) -> Option<(NonZeroU64, u64)> {
         let addr = unsafe { util_vma_heap_alloc(self.vm.deref_mut(), size, alignment) };
-        NonZeroU64::new(addr).map(|addr| VMA { vma: addr })
+        let addr = NonZeroU64::new(addr)?;
+
+        #[cfg(debug_assertions)]
+        self.track_alloc(addr.get(), size);
+
+        Some((addr, size))
+    }
+
+    // Pins the allocation at `address` instead of letting the heap pick a
+    // spot, for importing a userptr or a cross-process shared VA. Fails if
+    // the requested range is already occupied.
+    pub fn alloc_at(&mut self, address: u64, size: u64) -> Option<(NonZeroU64, u64)> {
+        // Validate up front, before committing anything in the heap: address
+        // is caller-supplied here (unlike alloc()'s heap-chosen address), and
+        // checking this after the util_vma_heap_alloc_addr() call below would
+        // mean bailing out via `?` with the range already carved out of the
+        // heap and no matching util_vma_heap_free to give it back.
+        let addr = NonZeroU64::new(address)?;
+
+        let ok = unsafe { util_vma_heap_alloc_addr(self.vm.deref_mut(), address, size) };
+        if !ok {
+            return None;
+        }
+
+        #[cfg(debug_assertions)]
+        self.track_alloc(addr.get(), size);
+
+        Some((addr, size))
+    }
+
+    // Same placement algorithm as alloc(), but from the top of the heap
+    // down, so callers can segregate some allocations (e.g. sparse
+    // reservations) into the high part of the address space without a
+    // second allocator.
+    pub fn alloc_high(&mut self, size: u64, alignment: u64) -> Option<(NonZeroU64, u64)> {
+        self.vm.alloc_high = true;
+        let addr = unsafe { util_vma_heap_alloc(self.vm.deref_mut(), size, alignment) };
+        self.vm.alloc_high = false;
+        let addr = NonZeroU64::new(addr)?;
+
+        #[cfg(debug_assertions)]
+        self.track_alloc(addr.get(), size);
+
+        Some((addr, size))
+    }
+
+    #[cfg(debug_assertions)]
+    fn track_alloc(&mut self, address: u64, size: u64) {
+        let prev = self.live.insert(address, size);
+        debug_assert!(
+            prev.is_none(),
+            "VMInner: address {:#x} allocated while already live",
+            address,
+        );
     }
 
-    // TODO: to guarantee a safe interface we should rather return a new object from alloc owning
-    // a reference to the vm and take care of the free via drop.
+    // Raw free, matching the (address, size) pair a prior alloc handed out.
+    // Prefer letting a VMA's Drop call this for you; this stays around as
+    // the primitive VMA::into_raw/from_raw round-trip through.
     pub fn free(&mut self, address: u64, size: u64) {
+        #[cfg(debug_assertions)]
+        self.track_free(address, size);
+
         unsafe {
             util_vma_heap_free(self.vm.deref_mut(), address, size);
         }
     }
 
+    #[cfg(debug_assertions)]
+    fn track_free(&mut self, address: u64, size: u64) {
+        match self.live.remove(&address) {
+            Some(tracked_size) => assert_eq!(
+                tracked_size, size,
+                "VMInner::free: {:#x} was allocated with size {:#x}, freed with size {:#x}",
+                address, tracked_size, size,
+            ),
+            None => {
+                // Not a known allocation start; see if it partially overlaps one.
+                if let Some((&other_addr, &other_size)) = self
+                    .live
+                    .range(..=address)
+                    .next_back()
+                    .filter(|&(&a, &s)| address < a + s)
+                {
+                    panic!(
+                        "VMInner::free: {:#x}..{:#x} partially overlaps live allocation {:#x}..{:#x}",
+                        address,
+                        address + size,
+                        other_addr,
+                        other_addr + other_size,
+                    );
+                }
+                panic!(
+                    "VMInner::free: {:#x} (size {:#x}) was never allocated, or already freed",
+                    address, size,
+                );
+            }
+        }
+    }
+
     fn new(start: u64, size: u64) -> Self {
         let mut vm = Box::pin(util_vma_heap::default());
 
@@ -38,24 +136,35 @@ impl VMInner {
             util_vma_heap_init(vm.deref_mut(), start, size);
         }
 
-        Self { vm: vm }
+        Self {
+            vm: vm,
+            #[cfg(debug_assertions)]
+            live: BTreeMap::new(),
+        }
     }
 }
 
 impl Drop for VMInner {
     fn drop(&mut self) {
+        #[cfg(debug_assertions)]
+        if !self.live.is_empty() {
+            for (&address, &size) in &self.live {
+                eprintln!(
+                    "VMInner: leaked allocation {:#x}..{:#x}",
+                    address,
+                    address + size,
+                );
+            }
+            panic!("VMInner dropped with {} leaked allocation(s)", self.live.len());
+        }
+
         unsafe {
             util_vma_heap_finish(self.vm.deref_mut());
         }
     }
 }
 
-// TODO: make this not suck so much
-//       the rough idea on what needs to change here is that VMA takes a reference to the inner
-//       mutex and uses it in drop to remove itself from the heap.
 pub struct VM {
-    // We need to pin the vma_heap because it's part of a linked list and cannot change its
-    // location.
     vm: Mutex<VMInner>,
 }
 
@@ -68,19 +177,339 @@ impl Deref for VM {
 }
 
 impl VM {
-    pub fn new(start: u64, size: u64) -> Self {
-        VM {
+    // Returned as an Arc so alloc() can hand out VMAs holding a Weak
+    // back-reference to self, which is what lets them free themselves on
+    // Drop instead of making every caller thread the address back to
+    // VMInner::free by hand.
+    pub fn new(start: u64, size: u64) -> Arc<VM> {
+        Arc::new(VM {
             vm: Mutex::new(VMInner::new(start, size)),
-        }
+        })
+    }
+
+    pub fn alloc(self: &Arc<Self>, size: u64, alignment: u64) -> Option<VMA> {
+        let (address, size) = self.vm.lock().unwrap().alloc(size, alignment)?;
+        Some(VMA {
+            vm: Arc::downgrade(self),
+            address,
+            size,
+        })
+    }
+
+    pub fn alloc_at(self: &Arc<Self>, address: u64, size: u64) -> Option<VMA> {
+        let (address, size) = self.vm.lock().unwrap().alloc_at(address, size)?;
+        Some(VMA {
+            vm: Arc::downgrade(self),
+            address,
+            size,
+        })
+    }
+
+    pub fn alloc_high(self: &Arc<Self>, size: u64, alignment: u64) -> Option<VMA> {
+        let (address, size) = self.vm.lock().unwrap().alloc_high(size, alignment)?;
+        Some(VMA {
+            vm: Arc::downgrade(self),
+            address,
+            size,
+        })
     }
 }
 
+// A self-freeing handle on a VM allocation: Drop locks the owning VM and
+// calls VMInner::free automatically, so callers can no longer forget to
+// pair an alloc with a free. mem::forget-ing a VMA (directly, or via
+// into_raw) only leaks the address range, not any actual memory -- a VMA
+// never grants access to memory, it just reserves a slice of VA space -- so
+// this is sound even though Drop isn't guaranteed to run.
 pub struct VMA {
-    vma: NonZeroU64,
+    vm: Weak<VM>,
+    address: NonZeroU64,
+    size: u64,
 }
 
 impl VMA {
     pub fn address(&self) -> NonZeroU64 {
-        self.vma
+        self.address
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    // Escape hatch for callers that must hand the raw range to C (e.g. an
+    // ioctl that takes ownership of the mapping): leaks self without
+    // running Drop and returns the (address, size) pair needed to free it
+    // later via `from_raw` or `VMInner::free`.
+    pub fn into_raw(self) -> (u64, u64) {
+        let pair = (self.address.get(), self.size);
+        std::mem::forget(self);
+        pair
+    }
+
+    // SAFETY: (address, size) must be a range that was actually allocated
+    // from `vm` and not already freed (e.g. the pair returned by a prior
+    // `into_raw` on a VMA allocated from this same VM).
+    pub unsafe fn from_raw(vm: &Arc<VM>, address: u64, size: u64) -> VMA {
+        VMA {
+            vm: Arc::downgrade(vm),
+            address: NonZeroU64::new(address).unwrap(),
+            size,
+        }
+    }
+}
+
+impl Drop for VMA {
+    fn drop(&mut self) {
+        // If the VM is already gone there's nothing left to free into.
+        if let Some(vm) = self.vm.upgrade() {
+            vm.vm.lock().unwrap().free(self.address.get(), self.size);
+        }
+    }
+}
+
+// Allocating every small GPU buffer straight from the global util_vma_heap
+// under one VM lock causes lock contention and external fragmentation once
+// a workload churns through thousands of tiny allocations. SubAllocator
+// reserves large fixed-size slabs from a VM up front, then carves each slab
+// into uniformly-sized blocks tracked by a per-slab free-list, so the hot
+// path (alloc/free of one of those blocks) only takes this SubAllocator's
+// slab-list lock (alloc only) and the slab's own lock, never the VM's.
+pub struct SubAllocator {
+    vm: Arc<VM>,
+    block_size: u64,
+    slab_size: u64,
+    alignment: u64,
+    slabs: Mutex<Vec<Arc<Slab>>>,
+}
+
+impl SubAllocator {
+    pub fn new(vm: Arc<VM>, block_size: u64, slab_size: u64, alignment: u64) -> Arc<SubAllocator> {
+        debug_assert!(slab_size >= block_size);
+        Arc::new(SubAllocator {
+            vm,
+            block_size,
+            slab_size,
+            alignment,
+            slabs: Mutex::new(Vec::new()),
+        })
+    }
+
+    // Anything bigger than a single block skips slab packing entirely and
+    // goes straight to the VM: it wouldn't fit more than one per slab
+    // anyway, and would just waste the rest of whichever slab it landed in.
+    pub fn alloc(self: &Arc<Self>, size: u64) -> Option<SubAlloc> {
+        if size > self.block_size {
+            return Some(SubAlloc::Direct(self.vm.alloc(size, self.alignment)?));
+        }
+
+        let mut slabs = self.slabs.lock().unwrap();
+        for slab in slabs.iter() {
+            if let Some(index) = slab.take_free_block() {
+                return Some(SubAlloc::Slab {
+                    owner: Arc::downgrade(self),
+                    address: slab.block_address(index),
+                    slab: slab.clone(),
+                    index,
+                });
+            }
+        }
+
+        // No existing slab has room: reserve a fresh one from the VM.
+        let num_blocks = (self.slab_size / self.block_size).max(1);
+        let vma = self
+            .vm
+            .alloc(self.block_size * num_blocks, self.alignment)?;
+        let slab = Arc::new(Slab::new(vma, self.block_size, num_blocks as usize));
+        let index = slab.take_free_block().unwrap();
+        let address = slab.block_address(index);
+        slabs.push(slab.clone());
+
+        Some(SubAlloc::Slab {
+            owner: Arc::downgrade(self),
+            slab,
+            index,
+            address,
+        })
+    }
+
+    // Called once a slab's last live block is freed: drops our reference to
+    // it, returning its VMA (and thus its whole address range) to the VM
+    // once every other Arc<Slab> reference (if any briefly race to reuse it
+    // first) is gone too.
+    fn reclaim_empty_slab(&self, slab: &Arc<Slab>) {
+        // alloc() only ever calls take_free_block() while holding this same
+        // slabs lock, so taking it here too closes the window where another
+        // thread's alloc() reuses a block from this slab (making it live
+        // again) between our caller observing it just went empty and us
+        // getting here -- re-check under the lock instead of trusting that
+        // observation, or we'd evict a slab that's no longer actually empty.
+        let mut slabs = self.slabs.lock().unwrap();
+        if slab.is_empty() {
+            slabs.retain(|s| !Arc::ptr_eq(s, slab));
+        }
+    }
+}
+
+struct SlabInner {
+    // Reservation backing this slab's whole address range; dropped once
+    // the slab is reclaimed, returning the range to the VM.
+    vma: VMA,
+    // One entry per block; true means free. A plain Vec<bool> rather than
+    // a packed bitmap -- simplicity over density, since a slab's block
+    // count is small enough that this doesn't matter in practice.
+    free: Vec<bool>,
+    live: usize,
+}
+
+struct Slab {
+    inner: Mutex<SlabInner>,
+    block_size: u64,
+}
+
+impl Slab {
+    fn new(vma: VMA, block_size: u64, num_blocks: usize) -> Slab {
+        Slab {
+            block_size,
+            inner: Mutex::new(SlabInner {
+                vma,
+                free: vec![true; num_blocks],
+                live: 0,
+            }),
+        }
+    }
+
+    fn take_free_block(&self) -> Option<usize> {
+        let mut inner = self.inner.lock().unwrap();
+        let index = inner.free.iter().position(|&free| free)?;
+        inner.free[index] = false;
+        inner.live += 1;
+        Some(index)
+    }
+
+    fn block_address(&self, index: usize) -> u64 {
+        let inner = self.inner.lock().unwrap();
+        inner.vma.address().get() + (index as u64) * self.block_size
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.lock().unwrap().live == 0
+    }
+
+    // Returns true if this was the slab's last live block.
+    fn return_block(&self, index: usize) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        debug_assert!(!inner.free[index], "Slab: block {} double-freed", index);
+        inner.free[index] = true;
+        inner.live -= 1;
+        inner.live == 0
+    }
+}
+
+// A handle to a sub-allocated block. Frees back to its owning slab's
+// free-list on Drop (or straight to the VM, for SubAlloc::Direct); an
+// emptied slab is returned to the VM the same way a VMA is.
+pub enum SubAlloc {
+    Direct(VMA),
+    Slab {
+        owner: Weak<SubAllocator>,
+        slab: Arc<Slab>,
+        index: usize,
+        address: u64,
+    },
+}
+
+impl SubAlloc {
+    pub fn address(&self) -> u64 {
+        match self {
+            SubAlloc::Direct(vma) => vma.address().get(),
+            SubAlloc::Slab { address, .. } => *address,
+        }
+    }
+}
+
+impl Drop for SubAlloc {
+    fn drop(&mut self) {
+        if let SubAlloc::Slab {
+            owner,
+            slab,
+            index,
+            ..
+        } = self
+        {
+            if slab.return_block(*index) {
+                if let Some(owner) = owner.upgrade() {
+                    owner.reclaim_empty_slab(slab);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // VMInner::free must catch a double-free instead of letting the second
+    // call corrupt util_vma_heap's hole list silently.
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "was never allocated, or already freed")]
+    fn double_free_panics_in_debug() {
+        let vm = VM::new(0x1000, 0x10000);
+        let vma = vm.alloc(0x1000, 0x1000).unwrap();
+        let (address, size) = vma.into_raw();
+
+        let mut inner = vm.lock().unwrap();
+        inner.free(address, size);
+        inner.free(address, size);
+    }
+
+    // alloc_at must reject both a zero address (which can't be represented
+    // as the NonZeroU64 a successful alloc hands back) and a range that
+    // overlaps one it already handed out, without leaking either range.
+    #[test]
+    fn alloc_at_rejects_zero_address_and_occupied_range() {
+        let vm = VM::new(0x1000, 0x10000);
+
+        assert!(vm.alloc_at(0, 0x1000).is_none());
+
+        let _first = vm.alloc_at(0x2000, 0x1000).unwrap();
+        assert!(vm.alloc_at(0x2000, 0x1000).is_none());
+    }
+
+    // A freed block must be handed back out by a later alloc() of the same
+    // size, rather than the slab only ever growing forward through its
+    // free-list.
+    #[test]
+    fn slab_blocks_are_reused_after_being_freed() {
+        let vm = VM::new(0x1000, 0x100000);
+        let sub = SubAllocator::new(vm, 0x100, 0x400, 0x100);
+
+        let a = sub.alloc(0x100).unwrap();
+        let b = sub.alloc(0x100).unwrap();
+        assert_ne!(a.address(), b.address());
+
+        let b_addr = b.address();
+        drop(b);
+
+        // `a` is still live, so the slab can't have been reclaimed -- this
+        // has to come from the same slab's free-list, and since only one
+        // block was ever freed, it has to be the one `b` just returned.
+        let c = sub.alloc(0x100).unwrap();
+        assert_eq!(c.address(), b_addr);
+    }
+
+    // Once a slab's only block is freed it's reclaimed (returned to the
+    // VM); a later alloc() of the same size must still succeed by carving a
+    // brand new slab, not fail or reuse the stale, now-unregistered Arc.
+    #[test]
+    fn slab_is_reclaimed_once_fully_freed() {
+        let vm = VM::new(0x1000, 0x100000);
+        let sub = SubAllocator::new(vm, 0x100, 0x100, 0x100);
+
+        let a = sub.alloc(0x100).unwrap();
+        drop(a);
+
+        assert!(sub.alloc(0x100).is_some());
     }
 }
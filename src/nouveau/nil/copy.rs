@@ -7,9 +7,12 @@ use crate::image::Image;
 use crate::tiling::{gob_height, Tiling, GOB_DEPTH, GOB_WIDTH_B};
 use crate::ILog2Ceil;
 
+use std::collections::HashMap;
 use std::ops::Range;
 use std::ffi::c_void;
 
+use rayon::prelude::*;
+
 pub const SECTOR_WIDTH_B: u32 = 16;
 pub const SECTOR_HEIGHT: u32 = 2;
 pub const SECTOR_SIZE_B: u32 = SECTOR_WIDTH_B * SECTOR_HEIGHT;
@@ -345,13 +348,164 @@ impl LinearPointer {
     }
 }
 
+// Runtime SIMD dispatch for the 16B sector copies, following the same
+// detect-once/dispatch-by-level pattern rav1e uses for its pixel kernels:
+// CpuFeatureLevel is probed a single time and cached, and the hot paths
+// (copy_whole_gob, in particular, since there the GOB is known to be whole
+// and aligned already) pick their kernel based on the cached level instead
+// of re-checking CPUID on every call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CpuFeatureLevel {
+    Scalar,
+    #[cfg(target_arch = "x86_64")]
+    Sse2,
+    #[cfg(target_arch = "x86_64")]
+    Ssse3,
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+}
+
+impl CpuFeatureLevel {
+    #[cfg(target_arch = "x86_64")]
+    fn detect() -> Self {
+        if is_x86_feature_detected!("avx2") {
+            Self::Avx2
+        } else if is_x86_feature_detected!("ssse3") {
+            Self::Ssse3
+        } else if is_x86_feature_detected!("sse2") {
+            Self::Sse2
+        } else {
+            Self::Scalar
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn detect() -> Self {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            Self::Neon
+        } else {
+            Self::Scalar
+        }
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    fn detect() -> Self {
+        Self::Scalar
+    }
+}
+
+static CPU_FEATURE_LEVEL: std::sync::OnceLock<CpuFeatureLevel> =
+    std::sync::OnceLock::new();
+
+// Exposed (instead of kept as a private cache behind copy_whole_gob) so
+// tests can force CpuFeatureLevel::Scalar and diff it against whatever the
+// host actually detects.
+pub(crate) fn cpu_feature_level() -> CpuFeatureLevel {
+    *CPU_FEATURE_LEVEL.get_or_init(CpuFeatureLevel::detect)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+unsafe fn copy16b_sse2(dst: *mut u8, src: *mut u8) {
+    use std::arch::x86_64::{_mm_loadu_si128, _mm_storeu_si128};
+    let v = _mm_loadu_si128(src as *const _);
+    _mm_storeu_si128(dst as *mut _, v);
+}
+
+#[cfg(target_arch = "aarch64")]
+unsafe fn copy16b_neon(dst: *mut u8, src: *mut u8) {
+    use std::arch::aarch64::{vld1q_u8, vst1q_u8};
+    let v = vld1q_u8(src);
+    vst1q_u8(dst, v);
+}
+
+// _mm_maskmoveu_si128 writes straight to memory, touching only the bytes
+// whose mask lane has its high bit set, so these can deinterleave a 16B GOB
+// chunk without a read-modify-write on the bytes another swizzle pass
+// already wrote (e.g. _Z24X8 must leave its X8 byte alone, since that byte
+// is where the _X24S8 pass puts the stencil).
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "ssse3")]
+unsafe fn copy16b_z24x8_to_tiled_ssse3(tiled: *mut u8, linear: *mut u8) {
+    use std::arch::x86_64::{_mm_loadu_si128, _mm_maskmoveu_si128};
+    const KEEP_LOW3_OF_4: [i8; 16] =
+        [-1, -1, -1, 0, -1, -1, -1, 0, -1, -1, -1, 0, -1, -1, -1, 0];
+    let v = _mm_loadu_si128(linear as *const _);
+    let mask = _mm_loadu_si128(KEEP_LOW3_OF_4.as_ptr() as *const _);
+    _mm_maskmoveu_si128(v, mask, tiled as *mut i8);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "ssse3")]
+unsafe fn copy16b_z24x8_to_linear_ssse3(tiled: *mut u8, linear: *mut u8) {
+    use std::arch::x86_64::{_mm_loadu_si128, _mm_maskmoveu_si128};
+    const KEEP_LOW3_OF_4: [i8; 16] =
+        [-1, -1, -1, 0, -1, -1, -1, 0, -1, -1, -1, 0, -1, -1, -1, 0];
+    let v = _mm_loadu_si128(tiled as *const _);
+    let mask = _mm_loadu_si128(KEEP_LOW3_OF_4.as_ptr() as *const _);
+    _mm_maskmoveu_si128(v, mask, linear as *mut i8);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "ssse3")]
+unsafe fn copy16b_x24s8_to_tiled_ssse3(tiled: *mut u8, linear: *mut u8) {
+    use std::arch::x86_64::{_mm_cvtsi32_si128, _mm_loadu_si128, _mm_maskmoveu_si128, _mm_shuffle_epi8};
+    // linear holds 4 packed stencil bytes for this chunk; scatter them into
+    // the high byte of each of the 4 tiled dwords.
+    const SCATTER: [i8; 16] =
+        [-1, -1, -1, 0, -1, -1, -1, 1, -1, -1, -1, 2, -1, -1, -1, 3];
+    const HIGH_BYTE_OF_4: [i8; 16] =
+        [0, 0, 0, -1, 0, 0, 0, -1, 0, 0, 0, -1, 0, 0, 0, -1];
+    let stencil = _mm_cvtsi32_si128(*(linear as *const i32));
+    let shuffled = _mm_shuffle_epi8(stencil, _mm_loadu_si128(SCATTER.as_ptr() as *const _));
+    let mask = _mm_loadu_si128(HIGH_BYTE_OF_4.as_ptr() as *const _);
+    _mm_maskmoveu_si128(shuffled, mask, tiled as *mut i8);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "ssse3")]
+unsafe fn copy16b_x24s8_to_linear_ssse3(tiled: *mut u8, linear: *mut u8) {
+    use std::arch::x86_64::{_mm_cvtsi128_si32, _mm_loadu_si128, _mm_shuffle_epi8};
+    // Gather the high byte of each of the 4 tiled dwords into the low 4
+    // bytes of a register, then store just those 4 bytes to the linear
+    // stencil plane.
+    const GATHER: [i8; 16] = [
+        3, 7, 11, 15, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+    ];
+    let v = _mm_loadu_si128(tiled as *const _);
+    let gathered = _mm_shuffle_epi8(v, _mm_loadu_si128(GATHER.as_ptr() as *const _));
+    *(linear as *mut i32) = _mm_cvtsi128_si32(gathered);
+}
+
 trait Copy16B {
     const X_DIVISOR: u32;
 
+    // Size in bytes of one tiled-side pixel. Lets the swizzle impls below
+    // express their edge loops as a whole number of pixels (Self::BPP)
+    // instead of repeating that same width as a bare step_by literal.
+    const BPP: usize = 1;
+
     unsafe fn copy(tiled: *mut u8, linear: *mut u8, bytes: usize);
     unsafe fn copy_16b(tiled: *mut [u8; 16], linear: *mut [u8; 16]) {
         Self::copy(tiled as *mut _, linear as *mut _, 16);
     }
+
+    // Used by the whole-GOB fast path, where every 16B chunk is known to be
+    // in-bounds. The default just falls back to the scalar copy_16b, which
+    // is always correct; individual impls override it with a real vector
+    // kernel where one applies (see RawCopyToTiled/RawCopyToLinear below).
+    #[inline]
+    unsafe fn copy_16b_simd(
+        tiled: *mut [u8; 16],
+        linear: *mut [u8; 16],
+        level: CpuFeatureLevel,
+    ) {
+        let _ = level;
+        Self::copy_16b(tiled, linear);
+    }
 }
 
 trait CopyGOB {
@@ -413,6 +567,12 @@ impl<C: Copy16B> CopyGOB for CopyGOB2D<C> {
         end: Offset4D<units::Bytes>,
     ) {
         debug_assert!(linear.x_divisor() == C::X_DIVISOR);
+        // The partial-chunk branch below does its edge clamping byte-wise
+        // within a 16B window, but Copy16B::copy() only knows how to place
+        // bytes at whole-pixel (BPP) granularity -- a region edge that
+        // splits a pixel would silently target the wrong byte of it.
+        debug_assert!(start.x as usize % C::BPP == 0);
+        debug_assert!(end.x as usize % C::BPP == 0);
         gob2d_for_each_16b(|offset, x, y| {
             let tiled = tiled + (offset as usize);
             let linear = linear.at(Offset4D::new(x, y, 0, 0));
@@ -423,7 +583,7 @@ impl<C: Copy16B> CopyGOB for CopyGOB2D<C> {
                 let end = std::cmp::min(end.x - x, 16) as usize;
                 C::copy(
                     (tiled + start) as *mut _,
-                    (linear + start) as *mut _,
+                    (linear + start / C::X_DIVISOR as usize) as *mut _,
                     end - start,
                 );
             }
@@ -432,15 +592,86 @@ impl<C: Copy16B> CopyGOB for CopyGOB2D<C> {
 
     unsafe fn copy_whole_gob(tiled: usize, linear: LinearPointer) {
         debug_assert!(linear.x_divisor() == C::X_DIVISOR);
+        let level = cpu_feature_level();
         gob2d_for_each_16b(|offset, x, y| {
             let tiled = tiled + (offset as usize);
             let linear = linear.at(Offset4D::new(x, y, 0, 0));
-            C::copy_16b(tiled as *mut _, linear as *mut _);
+            C::copy_16b_simd(tiled as *mut _, linear as *mut _, level);
         });
     }
 }
 
-unsafe fn copy_tile<CG: CopyGOB>(
+// A true 3D GOB: consecutive 2D GOB slices (CopyGOB2D::GOB_EXTENT_B each)
+// are packed back to back in Z before the block moves on to the next row,
+// so a block containing multiple Z slices is still a single contiguous
+// GOB column rather than `depth` independent 2D surfaces.
+struct CopyGOB3D<C: Copy16B> {
+    phantom: std::marker::PhantomData<C>,
+}
+
+const GOB_SLICE_SIZE_B: u32 = GOB_WIDTH_B * 8;
+
+fn gob3d_for_each_16b(mut f: impl FnMut(u32, u32, u32, u32)) {
+    for z in 0..GOB_DEPTH {
+        gob2d_for_each_16b(|offset, x, y| {
+            f(z * GOB_SLICE_SIZE_B + offset, x, y, z);
+        });
+    }
+}
+
+impl<C: Copy16B> CopyGOB for CopyGOB3D<C> {
+    const GOB_EXTENT_B: Extent4D<units::Bytes> =
+        Extent4D::new(GOB_WIDTH_B, 8, GOB_DEPTH, 1);
+
+    unsafe fn copy_gob(
+        tiled: usize,
+        linear: LinearPointer,
+        start: Offset4D<units::Bytes>,
+        end: Offset4D<units::Bytes>,
+    ) {
+        debug_assert!(linear.x_divisor() == C::X_DIVISOR);
+        // See the matching comment in CopyGOB2D::copy_gob: region edges must
+        // land on whole-pixel (BPP) boundaries, or the partial-chunk branch
+        // would target the wrong byte of a split pixel.
+        debug_assert!(start.x as usize % C::BPP == 0);
+        debug_assert!(end.x as usize % C::BPP == 0);
+        gob3d_for_each_16b(|offset, x, y, z| {
+            let tiled = tiled + (offset as usize);
+            let linear = linear.at(Offset4D::new(x, y, z, 0));
+            if x >= start.x
+                && x + 16 <= end.x
+                && z >= start.z
+                && z < end.z
+            {
+                C::copy_16b(tiled as *mut _, linear as *mut _);
+            } else if x + 16 >= start.x
+                && x < end.x
+                && z >= start.z
+                && z < end.z
+            {
+                let start = (std::cmp::max(x, start.x) - x) as usize;
+                let end = std::cmp::min(end.x - x, 16) as usize;
+                C::copy(
+                    (tiled + start) as *mut _,
+                    (linear + start / C::X_DIVISOR as usize) as *mut _,
+                    end - start,
+                );
+            }
+        });
+    }
+
+    unsafe fn copy_whole_gob(tiled: usize, linear: LinearPointer) {
+        debug_assert!(linear.x_divisor() == C::X_DIVISOR);
+        let level = cpu_feature_level();
+        gob3d_for_each_16b(|offset, x, y, z| {
+            let tiled = tiled + (offset as usize);
+            let linear = linear.at(Offset4D::new(x, y, z, 0));
+            C::copy_16b_simd(tiled as *mut _, linear as *mut _, level);
+        });
+    }
+}
+
+unsafe fn copy_tile_cg<CG: CopyGOB>(
     tiling: Tiling,
     tile_ptr: usize,
     linear: LinearPointer,
@@ -473,7 +704,64 @@ unsafe fn copy_tile<CG: CopyGOB>(
     }
 }
 
-unsafe fn copy_tiled<CG: CopyGOB>(
+// Picks CopyGOB2D or CopyGOB3D based on the tiling's actual GOB depth, since
+// that's only known at runtime (the same Copy16B kernel C is shared by both
+// block-linear images that happen to have block_depth == 1 and genuinely 3D
+// ones).
+unsafe fn copy_tile<C: Copy16B>(
+    tiling: Tiling,
+    tile_ptr: usize,
+    linear: LinearPointer,
+    start: Offset4D<units::Bytes>,
+    end: Offset4D<units::Bytes>,
+) {
+    if tiling.gob_extent_B().depth > 1 {
+        copy_tile_cg::<CopyGOB3D<C>>(tiling, tile_ptr, linear, start, end);
+    } else {
+        copy_tile_cg::<CopyGOB2D<C>>(tiling, tile_ptr, linear, start, end);
+    }
+}
+
+// On NVIDIA, the GOB block height must shrink for mip levels that are
+// shorter than the base block, or the block would extend past the level's
+// actual row count and the tail GOBs would never be addressed the way the
+// driver laid them out. Clamp y_log2 down so the block is never taller than
+// the level's height in GOBs rounded up to the next power of two (e.g. a
+// 3-GOB-tall level gets a 4-GOB block); x_log2/z_log2 are left alone since
+// block width/depth aren't mip-dependent here.
+fn clamped_tiling_for_level(
+    tiling: Tiling,
+    level_extent_B: Extent4D<units::Bytes>,
+) -> Tiling {
+    let gob_extent_B = tiling.gob_extent_B();
+    let level_height_gob =
+        level_extent_B.height.div_ceil(gob_extent_B.height).max(1);
+
+    let mut tiling = tiling;
+    tiling.y_log2 = std::cmp::min(tiling.y_log2, level_height_gob.ilog2_ceil());
+    tiling
+}
+
+// One item of tile-level work: copy_tiled splits the level into per-tile
+// byte ranges that never overlap (each tile owns a disjoint slice of both
+// the tiled and linear buffers), so once split they're trivially safe to
+// fan out across threads. usize is already Send; TileWork exists mainly to
+// document that disjointness invariant at the call site, the way
+// pathfinder's scene tiler keeps each tiler's output region separate.
+struct TileWork {
+    tile_ptr: usize,
+    linear: LinearPointer,
+    start: Offset4D<units::Bytes>,
+    end: Offset4D<units::Bytes>,
+}
+
+unsafe impl Send for TileWork {}
+
+// Below this many tiles, spinning up rayon's thread pool costs more than it
+// saves; fall back to the plain serial loop.
+const PARALLEL_TILE_THRESHOLD: usize = 8;
+
+unsafe fn copy_tiled<C: Copy16B + Sync>(
     tiling: Tiling,
     level_extent_B: Extent4D<units::Bytes>,
     level_tiled_ptr: usize,
@@ -481,6 +769,7 @@ unsafe fn copy_tiled<CG: CopyGOB>(
     start: Offset4D<units::Bytes>,
     end: Offset4D<units::Bytes>,
 ) {
+    let tiling = clamped_tiling_for_level(tiling, level_extent_B);
     let tile_extent_B = tiling.extent_B();
     let level_extent_B = level_extent_B.align(&tile_extent_B);
 
@@ -492,13 +781,302 @@ unsafe fn copy_tiled<CG: CopyGOB>(
     let level_tiled_ptr =
         BlockPointer::new(level_tiled_ptr, tile_extent_B, level_extent_B);
 
+    let mut tiles = Vec::new();
     for_each_extent4d(start, end, tile_extent_B, |tile, start, end| {
-        let tile_ptr = level_tiled_ptr.at(tile);
-        let linear = linear.offset(tile);
-        copy_tile::<CG>(tiling, tile_ptr, linear, start, end);
+        tiles.push(TileWork {
+            tile_ptr: level_tiled_ptr.at(tile),
+            linear: linear.offset(tile),
+            start,
+            end,
+        });
+    });
+
+    dispatch_tile_work::<C>(tiling, &tiles);
+}
+
+unsafe fn dispatch_tile_work<C: Copy16B + Sync>(tiling: Tiling, tiles: &[TileWork]) {
+    if tiles.len() >= PARALLEL_TILE_THRESHOLD {
+        tiles.par_iter().for_each(|tile| unsafe {
+            copy_tile::<C>(tiling, tile.tile_ptr, tile.linear, tile.start, tile.end);
+        });
+    } else {
+        for tile in tiles {
+            copy_tile::<C>(tiling, tile.tile_ptr, tile.linear, tile.start, tile.end);
+        }
+    }
+}
+
+// One damage rectangle in a multi-rect batch copy. Bytes throughout, like
+// the rest of this file's public entry points; the caller has already
+// converted from pixels via nil.format before getting here.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct CopyRect {
+    pub offset_B: Offset4D<units::Bytes>,
+    pub extent_B: Extent4D<units::Bytes>,
+}
+
+// Building block for the damage-region entry points below: walks every rect
+// in `rects`, buckets its tiles by physical tile pointer, and widens the
+// start/end bounds of any tile touched by more than one rect so it's copied
+// exactly once. This mirrors the damage-reduction check Intel SNA does
+// before migrating a surface (don't do work proportional to untouched
+// area, and don't redo work for tiles shared between rects).
+fn coalesce_damage_tiles(
+    tiling: Tiling,
+    level_extent_B: Extent4D<units::Bytes>,
+    level_tiled_ptr: usize,
+    linear: LinearPointer,
+    rects: &[CopyRect],
+) -> (Tiling, Vec<TileWork>) {
+    let tiling = clamped_tiling_for_level(tiling, level_extent_B);
+    let tile_extent_B = tiling.extent_B();
+    let level_extent_B = level_extent_B.align(&tile_extent_B);
+    let level_tiled_ptr =
+        BlockPointer::new(level_tiled_ptr, tile_extent_B, level_extent_B);
+
+    let mut tiles: HashMap<usize, TileWork> = HashMap::new();
+    for rect in rects {
+        let start = rect.offset_B;
+        let end = start + rect.extent_B;
+        // `linear` already points at the whole level's logical (0,0,0,0),
+        // matching copy_tiled's convention -- offset(tile) below is already
+        // relative to that shared origin, so don't also reverse() by this
+        // rect's own start (that would double up the offset for any rect
+        // that isn't itself at the origin).
+        for_each_extent4d(start, end, tile_extent_B, |tile, s, e| {
+            let tile_ptr = level_tiled_ptr.at(tile);
+            tiles
+                .entry(tile_ptr)
+                .and_modify(|w| {
+                    w.start = Offset4D::new(
+                        std::cmp::min(w.start.x, s.x),
+                        std::cmp::min(w.start.y, s.y),
+                        std::cmp::min(w.start.z, s.z),
+                        0,
+                    );
+                    w.end = Offset4D::new(
+                        std::cmp::max(w.end.x, e.x),
+                        std::cmp::max(w.end.y, e.y),
+                        std::cmp::max(w.end.z, e.z),
+                        1,
+                    );
+                })
+                .or_insert(TileWork {
+                    tile_ptr,
+                    linear: linear.offset(tile),
+                    start: s,
+                    end: e,
+                });
+        });
+    }
+
+    (tiling, tiles.into_values().collect())
+}
+
+unsafe fn copy_tiled_damage<C: Copy16B + Sync>(
+    tiling: Tiling,
+    level_extent_B: Extent4D<units::Bytes>,
+    level_tiled_ptr: usize,
+    linear: LinearPointer,
+    rects: &[CopyRect],
+) {
+    let (tiling, tiles) =
+        coalesce_damage_tiles(tiling, level_extent_B, level_tiled_ptr, linear, rects);
+    dispatch_tile_work::<C>(tiling, &tiles);
+}
+
+// Resolves a GOB-aligned logical byte offset within a level to its physical
+// address in a block-linear surface, by walking that surface's own tile grid
+// and then its own GOB grid. Two of these (one per side) let
+// copy_tiled_to_tiled address the same logical offset independently on each
+// side, which is what lets the two surfaces' block heights differ (their GOB
+// extents must still match).
+struct TiledLevel {
+    tiling: Tiling,
+    tile_extent_B: Extent4D<units::Bytes>,
+    level_tiled_ptr: BlockPointer,
+}
+
+impl TiledLevel {
+    fn new(
+        tiling: Tiling,
+        level_extent_B: Extent4D<units::Bytes>,
+        level_tiled_ptr: usize,
+    ) -> TiledLevel {
+        let tiling = clamped_tiling_for_level(tiling, level_extent_B);
+        let tile_extent_B = tiling.extent_B();
+        let level_extent_B = level_extent_B.align(&tile_extent_B);
+        TiledLevel {
+            tiling,
+            tile_extent_B,
+            level_tiled_ptr: BlockPointer::new(
+                level_tiled_ptr,
+                tile_extent_B,
+                level_extent_B,
+            ),
+        }
+    }
+
+    #[inline]
+    fn gob_ptr(&self, x: u32, y: u32, z: u32) -> usize {
+        let tile_extent_B = self.tile_extent_B;
+        let tile_x = x - x % tile_extent_B.width;
+        let tile_y = y - y % tile_extent_B.height;
+        let tile_z = z - z % tile_extent_B.depth;
+        let tile_ptr = self
+            .level_tiled_ptr
+            .at(Offset4D::new(tile_x, tile_y, tile_z, 0));
+
+        let gob_extent_B = self.tiling.gob_extent_B();
+        let tile_block_ptr =
+            BlockPointer::new(tile_ptr, gob_extent_B, tile_extent_B);
+        tile_block_ptr.at(Offset4D::new(x - tile_x, y - tile_y, z - tile_z, 0))
+    }
+}
+
+// Tiled <-> tiled copies never need a Copy16B kernel: both sides already
+// hold the packed tiled representation, a GOB's internal sector scramble
+// doesn't depend on the surrounding tiling, and there's no linear buffer
+// whose row stride the X_DIVISOR tricks exist to paper over. So unlike
+// copy_gob/copy_whole_gob above, these just move raw bytes between the two
+// resolved GOB addresses.
+
+unsafe fn copy_gob2d_tiled_to_tiled(
+    dst: usize,
+    src: usize,
+    start: Offset4D<units::Bytes>,
+    end: Offset4D<units::Bytes>,
+) {
+    gob2d_for_each_16b(|offset, x, _y| {
+        let dst = dst + (offset as usize);
+        let src = src + (offset as usize);
+        if x >= start.x && x + 16 <= end.x {
+            std::ptr::copy_nonoverlapping(src as *const u8, dst as *mut u8, 16);
+        } else if x + 16 >= start.x && x < end.x {
+            let s = (std::cmp::max(x, start.x) - x) as usize;
+            let e = std::cmp::min(end.x - x, 16) as usize;
+            std::ptr::copy_nonoverlapping(
+                (src + s) as *const u8,
+                (dst + s) as *mut u8,
+                e - s,
+            );
+        }
     });
 }
 
+unsafe fn copy_whole_gob2d_tiled_to_tiled(dst: usize, src: usize) {
+    gob2d_for_each_16b(|offset, _x, _y| {
+        std::ptr::copy_nonoverlapping(
+            (src + offset as usize) as *const u8,
+            (dst + offset as usize) as *mut u8,
+            16,
+        );
+    });
+}
+
+unsafe fn copy_gob3d_tiled_to_tiled(
+    dst: usize,
+    src: usize,
+    start: Offset4D<units::Bytes>,
+    end: Offset4D<units::Bytes>,
+) {
+    gob3d_for_each_16b(|offset, x, _y, z| {
+        let dst = dst + (offset as usize);
+        let src = src + (offset as usize);
+        if x >= start.x && x + 16 <= end.x && z >= start.z && z < end.z {
+            std::ptr::copy_nonoverlapping(src as *const u8, dst as *mut u8, 16);
+        } else if x + 16 >= start.x && x < end.x && z >= start.z && z < end.z {
+            let s = (std::cmp::max(x, start.x) - x) as usize;
+            let e = std::cmp::min(end.x - x, 16) as usize;
+            std::ptr::copy_nonoverlapping(
+                (src + s) as *const u8,
+                (dst + s) as *mut u8,
+                e - s,
+            );
+        }
+    });
+}
+
+unsafe fn copy_whole_gob3d_tiled_to_tiled(dst: usize, src: usize) {
+    gob3d_for_each_16b(|offset, _x, _y, _z| {
+        std::ptr::copy_nonoverlapping(
+            (src + offset as usize) as *const u8,
+            (dst + offset as usize) as *mut u8,
+            16,
+        );
+    });
+}
+
+// Copies directly between two block-linear surfaces, skipping the linear
+// staging buffer (and its bandwidth) that nil_copy_linear_to_tiled +
+// nil_copy_tiled_to_linear would otherwise need back to back for a
+// vkCopyImageToImage between two optimally-tiled images. The two surfaces
+// are each addressed through their own TiledLevel, so they're free to have
+// independent block dimensions (most commonly because
+// clamped_tiling_for_level shrank their block heights differently for
+// mismatched mip levels). Both surfaces must have the same GOB extent, i.e.
+// the same gob_height_is_8 and GOB depth; GOBs are copied as raw bytes, so
+// surfaces whose GOB shapes differ are not supported.
+unsafe fn copy_tiled_to_tiled(
+    dst_tiling: Tiling,
+    dst_level_extent_B: Extent4D<units::Bytes>,
+    dst_level_tiled_ptr: usize,
+    src_tiling: Tiling,
+    src_level_extent_B: Extent4D<units::Bytes>,
+    src_level_tiled_ptr: usize,
+    start: Offset4D<units::Bytes>,
+    end: Offset4D<units::Bytes>,
+) {
+    let dst = TiledLevel::new(dst_tiling, dst_level_extent_B, dst_level_tiled_ptr);
+    let src = TiledLevel::new(src_tiling, src_level_extent_B, src_level_tiled_ptr);
+
+    let gob_extent_B = dst.tiling.gob_extent_B();
+    debug_assert!(gob_extent_B == src.tiling.gob_extent_B());
+
+    for_each_extent4d(start, end, gob_extent_B, |gob, s, e| {
+        let dst_ptr = dst.gob_ptr(gob.x, gob.y, gob.z);
+        let src_ptr = src.gob_ptr(gob.x, gob.y, gob.z);
+        let whole = s == Offset4D::new(0, 0, 0, 0)
+            && e == Offset4D::new(0, 0, 0, 0) + gob_extent_B;
+        if gob_extent_B.depth > 1 {
+            if whole {
+                copy_whole_gob3d_tiled_to_tiled(dst_ptr, src_ptr);
+            } else {
+                copy_gob3d_tiled_to_tiled(dst_ptr, src_ptr, s, e);
+            }
+        } else if whole {
+            copy_whole_gob2d_tiled_to_tiled(dst_ptr, src_ptr);
+        } else {
+            copy_gob2d_tiled_to_tiled(dst_ptr, src_ptr, s, e);
+        }
+    });
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nil_copy_tiled_to_tiled(
+    tiled_dst: *mut c_void,
+    dst_level_extent_B: Extent4D<units::Bytes>,
+    dst_tiling: &Tiling,
+    tiled_src: *const c_void,
+    src_level_extent_B: Extent4D<units::Bytes>,
+    src_tiling: &Tiling,
+    offset_B: Offset4D<units::Bytes>,
+    extent_B: Extent4D<units::Bytes>,
+) {
+    let end_B = offset_B + extent_B;
+    copy_tiled_to_tiled(
+        *dst_tiling,
+        dst_level_extent_B,
+        tiled_dst as usize,
+        *src_tiling,
+        src_level_extent_B,
+        tiled_src as usize,
+        offset_B,
+        end_B,
+    );
+}
+
 struct RawCopyToTiled {}
 
 impl Copy16B for RawCopyToTiled {
@@ -508,6 +1086,25 @@ impl Copy16B for RawCopyToTiled {
         // This is backwards from memcpy
         std::ptr::copy_nonoverlapping(linear, tiled, bytes);
     }
+
+    #[inline]
+    unsafe fn copy_16b_simd(
+        tiled: *mut [u8; 16],
+        linear: *mut [u8; 16],
+        level: CpuFeatureLevel,
+    ) {
+        match level {
+            #[cfg(target_arch = "x86_64")]
+            CpuFeatureLevel::Sse2 | CpuFeatureLevel::Ssse3 | CpuFeatureLevel::Avx2 => {
+                copy16b_sse2(tiled as *mut u8, linear as *mut u8);
+            }
+            #[cfg(target_arch = "aarch64")]
+            CpuFeatureLevel::Neon => {
+                copy16b_neon(tiled as *mut u8, linear as *mut u8);
+            }
+            _ => Self::copy_16b(tiled, linear),
+        }
+    }
 }
 
 struct RawCopyToLinear {}
@@ -519,16 +1116,51 @@ impl Copy16B for RawCopyToLinear {
         // This is backwards from memcpy
         std::ptr::copy_nonoverlapping(tiled, linear, bytes);
     }
+
+    #[inline]
+    unsafe fn copy_16b_simd(
+        tiled: *mut [u8; 16],
+        linear: *mut [u8; 16],
+        level: CpuFeatureLevel,
+    ) {
+        match level {
+            #[cfg(target_arch = "x86_64")]
+            CpuFeatureLevel::Sse2 | CpuFeatureLevel::Ssse3 | CpuFeatureLevel::Avx2 => {
+                copy16b_sse2(linear as *mut u8, tiled as *mut u8);
+            }
+            #[cfg(target_arch = "aarch64")]
+            CpuFeatureLevel::Neon => {
+                copy16b_neon(linear as *mut u8, tiled as *mut u8);
+            }
+            _ => Self::copy_16b(tiled, linear),
+        }
+    }
 }
 
 struct CopyX24S8ToTiled {}
 
 impl Copy16B for CopyX24S8ToTiled {
     const X_DIVISOR: u32 = 4;
+    const BPP: usize = 4;
 
     unsafe fn copy(tiled: *mut u8, linear: *mut u8, bytes: usize) {
-        for i in (3..(bytes as isize)).step_by(4) {
-            tiled.offset(i).write(linear.offset(i / 4).read());
+        for i in ((Self::BPP as isize - 1)..(bytes as isize)).step_by(Self::BPP) {
+            tiled.offset(i).write(linear.offset(i / Self::BPP as isize).read());
+        }
+    }
+
+    #[inline]
+    unsafe fn copy_16b_simd(
+        tiled: *mut [u8; 16],
+        linear: *mut [u8; 16],
+        level: CpuFeatureLevel,
+    ) {
+        match level {
+            #[cfg(target_arch = "x86_64")]
+            CpuFeatureLevel::Ssse3 | CpuFeatureLevel::Avx2 => {
+                copy16b_x24s8_to_tiled_ssse3(tiled as *mut u8, linear as *mut u8);
+            }
+            _ => Self::copy_16b(tiled, linear),
         }
     }
 }
@@ -537,15 +1169,165 @@ struct CopyZ24X8ToTiled {}
 
 impl Copy16B for CopyZ24X8ToTiled {
     const X_DIVISOR: u32 = 1;
+    const BPP: usize = 4;
 
     unsafe fn copy(tiled: *mut u8, linear: *mut u8, bytes: usize) {
-        for i in (0..(bytes as isize)).step_by(4) {
+        for i in (0..(bytes as isize)).step_by(Self::BPP) {
             tiled.offset(i + 0).write(linear.offset(i + 0).read());
             tiled.offset(i + 1).write(linear.offset(i + 1).read());
             tiled.offset(i + 2).write(linear.offset(i + 2).read());
         }
     }
+
+    #[inline]
+    unsafe fn copy_16b_simd(
+        tiled: *mut [u8; 16],
+        linear: *mut [u8; 16],
+        level: CpuFeatureLevel,
+    ) {
+        match level {
+            #[cfg(target_arch = "x86_64")]
+            CpuFeatureLevel::Ssse3 | CpuFeatureLevel::Avx2 => {
+                copy16b_z24x8_to_tiled_ssse3(tiled as *mut u8, linear as *mut u8);
+            }
+            _ => Self::copy_16b(tiled, linear),
+        }
+    }
+}
+
+struct CopyX24S8ToLinear {}
+
+impl Copy16B for CopyX24S8ToLinear {
+    const X_DIVISOR: u32 = 4;
+    const BPP: usize = 4;
+
+    unsafe fn copy(tiled: *mut u8, linear: *mut u8, bytes: usize) {
+        for i in ((Self::BPP as isize - 1)..(bytes as isize)).step_by(Self::BPP) {
+            linear.offset(i / Self::BPP as isize).write(tiled.offset(i).read());
+        }
+    }
+
+    #[inline]
+    unsafe fn copy_16b_simd(
+        tiled: *mut [u8; 16],
+        linear: *mut [u8; 16],
+        level: CpuFeatureLevel,
+    ) {
+        match level {
+            #[cfg(target_arch = "x86_64")]
+            CpuFeatureLevel::Ssse3 | CpuFeatureLevel::Avx2 => {
+                copy16b_x24s8_to_linear_ssse3(tiled as *mut u8, linear as *mut u8);
+            }
+            _ => Self::copy_16b(tiled, linear),
+        }
+    }
 }
+
+struct CopyZ24X8ToLinear {}
+
+impl Copy16B for CopyZ24X8ToLinear {
+    const X_DIVISOR: u32 = 1;
+    const BPP: usize = 4;
+
+    unsafe fn copy(tiled: *mut u8, linear: *mut u8, bytes: usize) {
+        for i in (0..(bytes as isize)).step_by(Self::BPP) {
+            linear.offset(i + 0).write(tiled.offset(i + 0).read());
+            linear.offset(i + 1).write(tiled.offset(i + 1).read());
+            linear.offset(i + 2).write(tiled.offset(i + 2).read());
+        }
+    }
+
+    #[inline]
+    unsafe fn copy_16b_simd(
+        tiled: *mut [u8; 16],
+        linear: *mut [u8; 16],
+        level: CpuFeatureLevel,
+    ) {
+        match level {
+            #[cfg(target_arch = "x86_64")]
+            CpuFeatureLevel::Ssse3 | CpuFeatureLevel::Avx2 => {
+                copy16b_z24x8_to_linear_ssse3(tiled as *mut u8, linear as *mut u8);
+            }
+            _ => Self::copy_16b(tiled, linear),
+        }
+    }
+}
+
+// _Z32_X32 and _X32_X24S8 are the 8B/px combined depth/stencil formats. The
+// tiled pixel is an 8B group; unlike _Z24X8/_X24S8, the two halves of that
+// group are never interleaved bit-for-bit, so each direction only has to
+// gather/scatter the half it actually owns and leave the other half (the
+// X32/X24 don't-care bytes) untouched. This lets the depth (Z32) and stencil
+// (S8) components be sourced from the two distinct linear sub-images that
+// yuzu/Ryujinx-style emulators use for D32_SFLOAT_S8_UINT host copies,
+// instead of a single pre-interleaved buffer.
+
+struct CopyZ32X32ToTiled {}
+
+impl Copy16B for CopyZ32X32ToTiled {
+    // Only the low dword (Z32) is transferred; the linear plane is a packed
+    // D32 buffer, half the size of the combined 8B tiled pixel.
+    const X_DIVISOR: u32 = 2;
+    const BPP: usize = 8;
+
+    unsafe fn copy(tiled: *mut u8, linear: *mut u8, bytes: usize) {
+        for i in (0..(bytes as isize)).step_by(Self::BPP) {
+            let n = std::cmp::min(4, bytes - (i as usize));
+            std::ptr::copy_nonoverlapping(
+                linear.offset(i / Self::X_DIVISOR as isize),
+                tiled.offset(i),
+                n,
+            );
+        }
+    }
+}
+
+struct CopyZ32X32ToLinear {}
+
+impl Copy16B for CopyZ32X32ToLinear {
+    const X_DIVISOR: u32 = 2;
+    const BPP: usize = 8;
+
+    unsafe fn copy(tiled: *mut u8, linear: *mut u8, bytes: usize) {
+        for i in (0..(bytes as isize)).step_by(Self::BPP) {
+            let n = std::cmp::min(4, bytes - (i as usize));
+            std::ptr::copy_nonoverlapping(
+                tiled.offset(i),
+                linear.offset(i / Self::X_DIVISOR as isize),
+                n,
+            );
+        }
+    }
+}
+
+struct CopyX32X24S8ToTiled {}
+
+impl Copy16B for CopyX32X24S8ToTiled {
+    // Only the high byte of the second dword (the real S8 stencil value) is
+    // sourced from the linear plane, which is a packed 1Bpp S8 buffer.
+    const X_DIVISOR: u32 = 8;
+    const BPP: usize = 8;
+
+    unsafe fn copy(tiled: *mut u8, linear: *mut u8, bytes: usize) {
+        for i in ((Self::BPP as isize - 1)..(bytes as isize)).step_by(Self::BPP) {
+            tiled.offset(i).write(linear.offset(i / Self::X_DIVISOR as isize).read());
+        }
+    }
+}
+
+struct CopyX32X24S8ToLinear {}
+
+impl Copy16B for CopyX32X24S8ToLinear {
+    const X_DIVISOR: u32 = 8;
+    const BPP: usize = 8;
+
+    unsafe fn copy(tiled: *mut u8, linear: *mut u8, bytes: usize) {
+        for i in ((Self::BPP as isize - 1)..(bytes as isize)).step_by(Self::BPP) {
+            linear.offset(i / Self::X_DIVISOR as isize).write(tiled.offset(i).read());
+        }
+    }
+}
+
 #[derive(Clone, Debug, Copy, PartialEq, Default)]
 #[repr(u8)]
 pub enum CopySwizzle {
@@ -564,6 +1346,11 @@ pub unsafe extern "C" fn nil_copy_linear_to_tiled(
     linear_src: *const c_void,
     linear_row_stride_B: usize,
     linear_plane_stride_B: usize,
+    // Only used by _X32_X24S8, where the stencil byte lives in a linear
+    // sub-image distinct from the one addressed by linear_src.
+    stencil_src: *const c_void,
+    stencil_row_stride_B: usize,
+    stencil_plane_stride_B: usize,
     offset_B: Offset4D<units::Bytes>,
     extent_B: Extent4D<units::Bytes>,
     swizzle: CopySwizzle,
@@ -571,18 +1358,60 @@ pub unsafe extern "C" fn nil_copy_linear_to_tiled(
 ) {
     let end_B = offset_B + extent_B;
 
-    let linear_src = linear_src as usize;
     let tiled_dst = tiled_dst as usize;
-    let linear_pointer = LinearPointer::new(linear_src, 1, linear_row_stride_B, linear_plane_stride_B);
+    let linear_src = linear_src as usize;
+    let stencil_src = stencil_src as usize;
+
+    macro_rules! dispatch {
+        ($copy:ty, $base:expr, $row_stride:expr, $plane_stride:expr) => {
+            copy_tiled::<$copy>(
+                *tiling,
+                level_extent_B,
+                tiled_dst,
+                LinearPointer::new(
+                    $base,
+                    <$copy as Copy16B>::X_DIVISOR,
+                    $row_stride,
+                    $plane_stride,
+                ),
+                offset_B,
+                end_B,
+            )
+        };
+    }
 
-    copy_tiled::<CopyGOB2D<RawCopyToTiled>>(
-        *tiling,
-        level_extent_B,
-        tiled_dst,
-        linear_pointer,
-        offset_B,
-        end_B,
-    );
+    match swizzle {
+        CopySwizzle::_None => dispatch!(
+            RawCopyToTiled,
+            linear_src,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_Z24X8 => dispatch!(
+            CopyZ24X8ToTiled,
+            linear_src,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_X24S8 => dispatch!(
+            CopyX24S8ToTiled,
+            linear_src,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_Z32_X32 => dispatch!(
+            CopyZ32X32ToTiled,
+            linear_src,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_X32_X24S8 => dispatch!(
+            CopyX32X24S8ToTiled,
+            stencil_src,
+            stencil_row_stride_B,
+            stencil_plane_stride_B
+        ),
+    }
 }
 
 #[no_mangle]
@@ -590,6 +1419,11 @@ pub unsafe extern "C" fn nil_copy_tiled_to_linear(
     linear_dst: *mut c_void,
     linear_row_stride_B: usize,
     linear_plane_stride_B: usize,
+    // Only used by _X32_X24S8, where the stencil byte lives in a linear
+    // sub-image distinct from the one addressed by linear_dst.
+    stencil_dst: *mut c_void,
+    stencil_row_stride_B: usize,
+    stencil_plane_stride_B: usize,
     tiled_src: *const c_void,
     level_extent_B: Extent4D<units::Bytes>,
     offset_B: Offset4D<units::Bytes>,
@@ -599,18 +1433,210 @@ pub unsafe extern "C" fn nil_copy_tiled_to_linear(
 ) {
     let end_B = offset_B + extent_B;
 
+    let tiled_src = tiled_src as usize;
     let linear_dst = linear_dst as usize;
+    let stencil_dst = stencil_dst as usize;
+
+    macro_rules! dispatch {
+        ($copy:ty, $base:expr, $row_stride:expr, $plane_stride:expr) => {
+            copy_tiled::<$copy>(
+                *tiling,
+                level_extent_B,
+                tiled_src,
+                LinearPointer::new(
+                    $base,
+                    <$copy as Copy16B>::X_DIVISOR,
+                    $row_stride,
+                    $plane_stride,
+                ),
+                offset_B,
+                end_B,
+            )
+        };
+    }
+
+    match swizzle {
+        CopySwizzle::_None => dispatch!(
+            RawCopyToLinear,
+            linear_dst,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_Z24X8 => dispatch!(
+            CopyZ24X8ToLinear,
+            linear_dst,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_X24S8 => dispatch!(
+            CopyX24S8ToLinear,
+            linear_dst,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_Z32_X32 => dispatch!(
+            CopyZ32X32ToLinear,
+            linear_dst,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_X32_X24S8 => dispatch!(
+            CopyX32X24S8ToLinear,
+            stencil_dst,
+            stencil_row_stride_B,
+            stencil_plane_stride_B
+        ),
+    }
+}
+
+// Batch variants of nil_copy_linear_to_tiled/nil_copy_tiled_to_linear for a
+// client (e.g. a damage-tracked swapchain blit) that has several disjoint
+// dirty sub-rectangles to copy in one go: tiles shared between rects are
+// only ever touched once, and untouched tiles aren't visited at all, rather
+// than the whole surface being re-walked once per rect.
+
+#[no_mangle]
+pub unsafe extern "C" fn nil_copy_linear_to_tiled_damage(
+    tiled_dst: *mut c_void,
+    level_extent_B: Extent4D<units::Bytes>,
+    linear_src: *const c_void,
+    linear_row_stride_B: usize,
+    linear_plane_stride_B: usize,
+    stencil_src: *const c_void,
+    stencil_row_stride_B: usize,
+    stencil_plane_stride_B: usize,
+    rects: *const CopyRect,
+    rect_count: usize,
+    swizzle: CopySwizzle,
+    tiling: &Tiling,
+) {
+    let rects = std::slice::from_raw_parts(rects, rect_count);
+
+    let tiled_dst = tiled_dst as usize;
+    let linear_src = linear_src as usize;
+    let stencil_src = stencil_src as usize;
+
+    macro_rules! dispatch {
+        ($copy:ty, $base:expr, $row_stride:expr, $plane_stride:expr) => {
+            copy_tiled_damage::<$copy>(
+                *tiling,
+                level_extent_B,
+                tiled_dst,
+                LinearPointer::new(
+                    $base,
+                    <$copy as Copy16B>::X_DIVISOR,
+                    $row_stride,
+                    $plane_stride,
+                ),
+                rects,
+            )
+        };
+    }
+
+    match swizzle {
+        CopySwizzle::_None => dispatch!(
+            RawCopyToTiled,
+            linear_src,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_Z24X8 => dispatch!(
+            CopyZ24X8ToTiled,
+            linear_src,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_X24S8 => dispatch!(
+            CopyX24S8ToTiled,
+            linear_src,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_Z32_X32 => dispatch!(
+            CopyZ32X32ToTiled,
+            linear_src,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_X32_X24S8 => dispatch!(
+            CopyX32X24S8ToTiled,
+            stencil_src,
+            stencil_row_stride_B,
+            stencil_plane_stride_B
+        ),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nil_copy_tiled_to_linear_damage(
+    linear_dst: *mut c_void,
+    linear_row_stride_B: usize,
+    linear_plane_stride_B: usize,
+    stencil_dst: *mut c_void,
+    stencil_row_stride_B: usize,
+    stencil_plane_stride_B: usize,
+    tiled_src: *const c_void,
+    level_extent_B: Extent4D<units::Bytes>,
+    rects: *const CopyRect,
+    rect_count: usize,
+    swizzle: CopySwizzle,
+    tiling: &Tiling,
+) {
+    let rects = std::slice::from_raw_parts(rects, rect_count);
+
     let tiled_src = tiled_src as usize;
-    let linear_pointer = LinearPointer::new(linear_dst, 1, linear_row_stride_B, linear_plane_stride_B);
+    let linear_dst = linear_dst as usize;
+    let stencil_dst = stencil_dst as usize;
+
+    macro_rules! dispatch {
+        ($copy:ty, $base:expr, $row_stride:expr, $plane_stride:expr) => {
+            copy_tiled_damage::<$copy>(
+                *tiling,
+                level_extent_B,
+                tiled_src,
+                LinearPointer::new(
+                    $base,
+                    <$copy as Copy16B>::X_DIVISOR,
+                    $row_stride,
+                    $plane_stride,
+                ),
+                rects,
+            )
+        };
+    }
 
-    copy_tiled::<CopyGOB2D<RawCopyToLinear>>(
-        *tiling,
-        level_extent_B,
-        tiled_src,
-        linear_pointer,
-        offset_B,
-        end_B,
-    );
+    match swizzle {
+        CopySwizzle::_None => dispatch!(
+            RawCopyToLinear,
+            linear_dst,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_Z24X8 => dispatch!(
+            CopyZ24X8ToLinear,
+            linear_dst,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_X24S8 => dispatch!(
+            CopyX24S8ToLinear,
+            linear_dst,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_Z32_X32 => dispatch!(
+            CopyZ32X32ToLinear,
+            linear_dst,
+            linear_row_stride_B,
+            linear_plane_stride_B
+        ),
+        CopySwizzle::_X32_X24S8 => dispatch!(
+            CopyX32X24S8ToLinear,
+            stencil_dst,
+            stencil_row_stride_B,
+            stencil_plane_stride_B
+        ),
+    }
 }
 
 /* TODO: Just leaving this here in case we need it for anything, otherwise will delete for merge
@@ -1243,3 +2269,390 @@ impl LinearTiledCopy for CopyLinearToTiled {
     }
 }
     */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // copy_16b_simd must agree byte-for-byte with the scalar kernel it's
+    // meant to speed up, regardless of which CpuFeatureLevel the host
+    // actually detects.
+    #[test]
+    fn raw_copy_simd_matches_scalar() {
+        let src: [u8; 16] = std::array::from_fn(|i| i as u8);
+
+        let mut scalar_out = [0u8; 16];
+        unsafe {
+            RawCopyToTiled::copy_16b(
+                &mut scalar_out as *mut _,
+                &src.clone() as *const _ as *mut _,
+            );
+        }
+
+        let mut simd_out = [0u8; 16];
+        unsafe {
+            RawCopyToTiled::copy_16b_simd(
+                &mut simd_out as *mut _,
+                &src.clone() as *const _ as *mut _,
+                cpu_feature_level(),
+            );
+        }
+
+        assert_eq!(scalar_out, simd_out);
+    }
+
+    #[test]
+    fn raw_copy_simd_matches_scalar_forced_to_scalar() {
+        let src: [u8; 16] = std::array::from_fn(|i| 0xa0 + i as u8);
+
+        let mut scalar_out = [0u8; 16];
+        unsafe {
+            RawCopyToLinear::copy_16b(
+                &mut scalar_out as *mut _,
+                &src.clone() as *const _ as *mut _,
+            );
+        }
+
+        let mut forced_out = [0u8; 16];
+        unsafe {
+            RawCopyToLinear::copy_16b_simd(
+                &mut forced_out as *mut _,
+                &src.clone() as *const _ as *mut _,
+                CpuFeatureLevel::Scalar,
+            );
+        }
+
+        assert_eq!(scalar_out, forced_out);
+    }
+
+    // A full mip chain from 1x1 up to a large base level: at every level the
+    // clamped block height must never exceed the level's height in GOBs
+    // rounded up to a power of two, and must never be clamped below what the
+    // level can actually use (a level as tall as the unclamped block keeps
+    // the full block height).
+    #[test]
+    fn clamp_tracks_mip_chain() {
+        let base = Tiling::new(0, 4, 0, true);
+        let gob_h = base.gob_extent_B().height;
+        let base_height_B = gob_h * (1 << base.y_log2);
+
+        let mut level_height_B = base_height_B;
+        while level_height_B >= 1 {
+            let level_extent_B = Extent4D::new(1, level_height_B, 1, 1);
+            let clamped = clamped_tiling_for_level(base, level_extent_B);
+
+            let clamped_height_gob = 1u32 << clamped.y_log2;
+            let needed_height_gob = level_height_B.div_ceil(gob_h).max(1);
+
+            assert!(clamped.y_log2 <= base.y_log2);
+            assert!(clamped_height_gob >= needed_height_gob);
+            assert!(clamped_height_gob < needed_height_gob * 2);
+
+            level_height_B /= 2;
+        }
+    }
+
+    // dispatch_tile_work fans tiles out across rayon's thread pool once
+    // there are enough of them, but that's purely a throughput decision --
+    // the same PARALLEL_TILE_THRESHOLD-or-more tiles must land in exactly
+    // the same bytes as the plain serial loop below it would produce.
+    #[test]
+    fn dispatch_tile_work_parallel_matches_serial() {
+        let tiling = Tiling::new(0, 0, 0, true);
+        let tile_extent_B = tiling.extent_B();
+        let tile_bytes = (tile_extent_B.width * tile_extent_B.height * tile_extent_B.depth)
+            as usize;
+        let row_stride = tile_extent_B.width as usize;
+        let plane_stride = row_stride * tile_extent_B.height as usize;
+
+        let tile_count = PARALLEL_TILE_THRESHOLD;
+        let total_bytes = tile_bytes * tile_count;
+
+        let linear_src: Vec<u8> = (0..total_bytes).map(|i| i as u8).collect();
+        let mut tiled_parallel = vec![0xeeu8; total_bytes];
+        let mut tiled_serial = vec![0xeeu8; total_bytes];
+
+        let make_tiles = |tiled_ptr: usize| -> Vec<TileWork> {
+            (0..tile_count)
+                .map(|i| TileWork {
+                    tile_ptr: tiled_ptr + i * tile_bytes,
+                    linear: LinearPointer::new(
+                        linear_src.as_ptr() as usize + i * tile_bytes,
+                        1,
+                        row_stride,
+                        plane_stride,
+                    ),
+                    start: Offset4D::new(0, 0, 0, 0),
+                    end: Offset4D::new(0, 0, 0, 0) + tile_extent_B,
+                })
+                .collect()
+        };
+
+        let tiles_parallel = make_tiles(tiled_parallel.as_mut_ptr() as usize);
+        assert!(tiles_parallel.len() >= PARALLEL_TILE_THRESHOLD);
+        unsafe {
+            dispatch_tile_work::<RawCopyToTiled>(tiling, &tiles_parallel);
+        }
+
+        let tiles_serial = make_tiles(tiled_serial.as_mut_ptr() as usize);
+        for tile in &tiles_serial {
+            unsafe {
+                copy_tile::<RawCopyToTiled>(tiling, tile.tile_ptr, tile.linear, tile.start, tile.end);
+            }
+        }
+
+        // Each tile here is a single GOB, so the expected output is just the
+        // linear source run through the GOB's 16B-chunk swizzle.
+        let mut expected = vec![0u8; total_bytes];
+        for i in 0..tile_count {
+            gob2d_for_each_16b(|offset, x, y| {
+                let t = i * tile_bytes + offset as usize;
+                let l = i * tile_bytes + (y as usize) * row_stride + x as usize;
+                expected[t..t + 16].copy_from_slice(&linear_src[l..l + 16]);
+            });
+        }
+        assert_eq!(tiled_parallel, expected);
+        assert_eq!(tiled_parallel, tiled_serial);
+    }
+
+    // A damage rect that isn't at the level's origin must still resolve to
+    // the same linear address copy_tiled would use for that tile -- i.e.
+    // `linear` (already anchored at the whole level's logical 0,0,0,0)
+    // offset by the tile's own logical position, not "reversed" by the
+    // rect's start first.
+    #[test]
+    fn coalesce_damage_tiles_handles_nonorigin_rect() {
+        let tiling = Tiling::new(0, 0, 0, true);
+        let tile_extent_B = tiling.extent_B();
+        let level_extent_B =
+            Extent4D::new(tile_extent_B.width * 2, tile_extent_B.height * 2, 1, 1);
+
+        let linear = LinearPointer::new(0x2000, 1, tile_extent_B.width as usize * 2, 0);
+
+        // A whole tile's worth of damage in the second tile row/column, away
+        // from the level's origin.
+        let rect = CopyRect {
+            offset_B: Offset4D::new(tile_extent_B.width, tile_extent_B.height, 0, 0),
+            extent_B: Extent4D::new(tile_extent_B.width, tile_extent_B.height, 1, 1),
+        };
+
+        let (_, tiles) =
+            coalesce_damage_tiles(tiling, level_extent_B, 0x1000, linear, &[rect]);
+
+        assert_eq!(tiles.len(), 1);
+        let expected_linear = linear.at(rect.offset_B);
+        assert_eq!(
+            tiles[0].linear.at(Offset4D::new(0, 0, 0, 0)),
+            expected_linear
+        );
+    }
+
+    // CopyGOB2D::copy_gob's partial-chunk branch (taken whenever a region
+    // edge falls mid-GOB, i.e. isn't aligned to the 16B chunk size) has to
+    // scale the tiled-space byte offset it adds to `linear` down by
+    // X_DIVISOR, same as every other place that walks a Copy16B impl's
+    // linear side. X24S8 (X_DIVISOR == 4) only ever writes the high byte of
+    // each tiled dword, so this pins both "which bytes get touched" and
+    // "which linear byte lands in each one" down for a region that starts
+    // and ends off a 16B boundary.
+    #[test]
+    fn x24s8_to_tiled_partial_chunk_respects_x_divisor() {
+        let mut tiled = [0xeeu8; 512];
+        let linear: [u8; 128] = std::array::from_fn(|i| i as u8);
+
+        let start = Offset4D::new(20, 0, 0, 0);
+        let end = Offset4D::new(44, 8, 1, 1);
+
+        unsafe {
+            CopyGOB2D::<CopyX24S8ToTiled>::copy_gob(
+                tiled.as_mut_ptr() as usize,
+                LinearPointer::new(linear.as_ptr() as usize, 4, 16, 0),
+                start,
+                end,
+            );
+        }
+
+        gob2d_for_each_16b(|offset, x, y| {
+            let full = x >= start.x && x + 16 <= end.x;
+            let partial = !full && x + 16 >= start.x && x < end.x;
+            for i in 0..16usize {
+                let tiled_byte = tiled[offset as usize + i];
+                let in_range = if full {
+                    true
+                } else if partial {
+                    let local_start = (std::cmp::max(x, start.x) - x) as usize;
+                    let local_end = std::cmp::min(end.x - x, 16) as usize;
+                    i >= local_start && i < local_end
+                } else {
+                    false
+                };
+                if in_range && i % 4 == 3 {
+                    let expected_linear_idx = (y as usize) * 16 + (x as usize + i) / 4;
+                    assert_eq!(
+                        tiled_byte, linear[expected_linear_idx],
+                        "x={x} y={y} i={i}"
+                    );
+                } else {
+                    assert_eq!(tiled_byte, 0xee, "x={x} y={y} i={i} should be untouched");
+                }
+            }
+        });
+    }
+
+    // CopyGOB2D::copy_gob's partial-chunk branch debug_asserts its region
+    // edges land on whole-pixel (BPP) boundaries -- a start/end that splits
+    // a swizzled pixel would silently target the wrong byte of it.
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic]
+    fn x24s8_to_tiled_partial_chunk_rejects_misaligned_edge() {
+        let mut tiled = [0xeeu8; 512];
+        let linear = [0u8; 128];
+
+        unsafe {
+            CopyGOB2D::<CopyX24S8ToTiled>::copy_gob(
+                tiled.as_mut_ptr() as usize,
+                LinearPointer::new(linear.as_ptr() as usize, 4, 16, 0),
+                Offset4D::new(21, 0, 0, 0),
+                Offset4D::new(44, 8, 1, 1),
+            );
+        }
+    }
+
+    // CopyGOB3D::copy_gob (gob3d_for_each_16b's Z-interleaved addressing)
+    // had no test coverage at all. This is the 3D analogue of
+    // x24s8_to_tiled_partial_chunk_respects_x_divisor above: the same
+    // non-16-byte-aligned region, now checked across every Z slice of a 3D
+    // GOB column instead of a single 2D one.
+    #[test]
+    fn x24s8_to_tiled_3d_partial_chunk_respects_x_divisor() {
+        let extent = <CopyGOB3D<CopyX24S8ToTiled> as CopyGOB>::GOB_EXTENT_B;
+        let row_stride = (extent.width / CopyX24S8ToTiled::X_DIVISOR) as usize;
+        let plane_stride = row_stride * extent.height as usize;
+
+        let mut tiled = vec![0xeeu8; (extent.width * extent.height * extent.depth) as usize];
+        let linear: Vec<u8> = (0..plane_stride * extent.depth as usize)
+            .map(|i| i as u8)
+            .collect();
+
+        let start = Offset4D::new(20, 0, 0, 0);
+        let end = Offset4D::new(44, extent.height, extent.depth, 1);
+
+        unsafe {
+            CopyGOB3D::<CopyX24S8ToTiled>::copy_gob(
+                tiled.as_mut_ptr() as usize,
+                LinearPointer::new(linear.as_ptr() as usize, 4, row_stride, plane_stride),
+                start,
+                end,
+            );
+        }
+
+        gob3d_for_each_16b(|offset, x, y, z| {
+            let full = x >= start.x && x + 16 <= end.x && z >= start.z && z < end.z;
+            let partial =
+                !full && x + 16 >= start.x && x < end.x && z >= start.z && z < end.z;
+            for i in 0..16usize {
+                let tiled_byte = tiled[offset as usize + i];
+                let in_range = if full {
+                    true
+                } else if partial {
+                    let local_start = (std::cmp::max(x, start.x) - x) as usize;
+                    let local_end = std::cmp::min(end.x - x, 16) as usize;
+                    i >= local_start && i < local_end
+                } else {
+                    false
+                };
+                if in_range && i % 4 == 3 {
+                    let expected_linear_idx = (z as usize) * plane_stride
+                        + (y as usize) * row_stride
+                        + (x as usize + i) / 4;
+                    assert_eq!(
+                        tiled_byte, linear[expected_linear_idx],
+                        "x={x} y={y} z={z} i={i}"
+                    );
+                } else {
+                    assert_eq!(
+                        tiled_byte, 0xee,
+                        "x={x} y={y} z={z} i={i} should be untouched"
+                    );
+                }
+            }
+        });
+    }
+
+    // The SSSE3 deinterleave kernels must agree byte-for-byte with the
+    // scalar reference loop they replace in the whole-GOB fast path.
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn z24x8_and_x24s8_simd_match_scalar() {
+        if !is_x86_feature_detected!("ssse3") {
+            return;
+        }
+
+        let tiled_src: [u8; 16] = std::array::from_fn(|i| i as u8);
+        let linear_src: [u8; 16] = std::array::from_fn(|i| 0x40 + i as u8);
+
+        let mut scalar_tiled = tiled_src;
+        let mut simd_tiled = tiled_src;
+        unsafe {
+            CopyZ24X8ToTiled::copy_16b(
+                &mut scalar_tiled as *mut _,
+                &mut linear_src.clone() as *mut _,
+            );
+            CopyZ24X8ToTiled::copy_16b_simd(
+                &mut simd_tiled as *mut _,
+                &mut linear_src.clone() as *mut _,
+                CpuFeatureLevel::Ssse3,
+            );
+        }
+        assert_eq!(scalar_tiled, simd_tiled);
+
+        let mut scalar_linear = linear_src;
+        let mut simd_linear = linear_src;
+        unsafe {
+            CopyZ24X8ToLinear::copy_16b(
+                &mut tiled_src.clone() as *mut _,
+                &mut scalar_linear as *mut _,
+            );
+            CopyZ24X8ToLinear::copy_16b_simd(
+                &mut tiled_src.clone() as *mut _,
+                &mut simd_linear as *mut _,
+                CpuFeatureLevel::Ssse3,
+            );
+        }
+        assert_eq!(scalar_linear, simd_linear);
+
+        // CopyX24S8's linear plane only needs 4 bytes per 16B tiled chunk.
+        let stencil_src: [u8; 16] = std::array::from_fn(|i| 0x80 + i as u8);
+
+        let mut scalar_tiled = tiled_src;
+        let mut simd_tiled = tiled_src;
+        unsafe {
+            CopyX24S8ToTiled::copy_16b(
+                &mut scalar_tiled as *mut _,
+                &mut stencil_src.clone() as *mut _,
+            );
+            CopyX24S8ToTiled::copy_16b_simd(
+                &mut simd_tiled as *mut _,
+                &mut stencil_src.clone() as *mut _,
+                CpuFeatureLevel::Ssse3,
+            );
+        }
+        assert_eq!(scalar_tiled, simd_tiled);
+
+        let mut scalar_linear = stencil_src;
+        let mut simd_linear = stencil_src;
+        unsafe {
+            CopyX24S8ToLinear::copy_16b(
+                &mut tiled_src.clone() as *mut _,
+                &mut scalar_linear as *mut _,
+            );
+            CopyX24S8ToLinear::copy_16b_simd(
+                &mut tiled_src.clone() as *mut _,
+                &mut simd_linear as *mut _,
+                CpuFeatureLevel::Ssse3,
+            );
+        }
+        assert_eq!(scalar_linear[0..4], simd_linear[0..4]);
+    }
+}